@@ -1,9 +1,17 @@
+#![cfg_attr(not(feature = "std"), no_std)]
 #![cfg_attr(feature = "nightly", feature(try_from))]
-#[cfg(feature = "nightly")]
+
+#[cfg(all(feature = "nightly", feature = "std"))]
 use std::convert::TryFrom;
+#[cfg(feature = "std")]
 use std::fmt;
+#[cfg(feature = "std")]
 use std::io;
 
+#[cfg(not(feature = "std"))]
+use core::fmt;
+use core::str;
+
 #[derive(Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub struct OsError {
     code: i32,
@@ -12,6 +20,39 @@ pub struct OsError {
 #[derive(Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Debug, Hash)]
 pub struct NoOsError;
 
+/// A platform-independent classification of an OS error code.
+///
+/// Mirrors the subset of categories that `std::io::ErrorKind` already maps
+/// OS error codes to (e.g. Linux code `98` and Windows code `10048` both
+/// represent `AddrInUse`), so errors captured on different platforms can be
+/// compared.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub enum PortableErrno {
+    NotFound,
+    PermissionDenied,
+    ConnectionRefused,
+    ConnectionReset,
+    ConnectionAborted,
+    NotConnected,
+    AddrInUse,
+    AddrNotAvailable,
+    BrokenPipe,
+    AlreadyExists,
+    WouldBlock,
+    InvalidInput,
+    TimedOut,
+    Interrupted,
+    Other,
+}
+
+/// An operating system family that `OsError` codes can be translated
+/// between with [`OsError::translate_to`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub enum Platform {
+    Linux,
+    Windows,
+}
+
 impl OsError {
     /// Creates a new instance of an `OsError` from a particular OS error code.
     ///
@@ -20,33 +61,40 @@ impl OsError {
     /// On Linux:
     ///
     /// ```
-    /// # if cfg!(target_os = "linux") {
+    /// # #[cfg(feature = "std")]
+    /// # {
     /// use std::io;
     ///
-    /// let error = os_error::OsError::new(98);
-    /// assert_eq!(error.kind(), io::ErrorKind::AddrInUse);
+    /// if cfg!(target_os = "linux") {
+    ///     let error = os_error::OsError::new(98);
+    ///     assert_eq!(error.kind(), io::ErrorKind::AddrInUse);
+    /// }
     /// # }
     /// ```
     ///
     /// On Windows:
     ///
     /// ```
-    /// # if cfg!(windows) {
+    /// # #[cfg(feature = "std")]
+    /// # {
     /// use std::io;
     ///
-    /// let error = os_error::OsError::new(10048);
-    /// assert_eq!(error.kind(), io::ErrorKind::AddrInUse);
+    /// if cfg!(windows) {
+    ///     let error = os_error::OsError::new(10048);
+    ///     assert_eq!(error.kind(), io::ErrorKind::AddrInUse);
+    /// }
     /// # }
     /// ```
     pub fn new(code: i32) -> OsError {
-        OsError { code: code }
+        OsError { code }
     }
 
     /// Returns an error representing the last OS error which occurred.
     ///
-    /// This function reads the value of `errno` for the target platform (e.g.
-    /// `GetLastError` on Windows) and will return a corresponding instance of
-    /// `OsError` for the error code.
+    /// This function reads the value of `errno` for the target platform
+    /// directly (`*libc::__errno_location()` on unix, `GetLastError()` on
+    /// Windows) rather than going through `std::io::Error`, so it is
+    /// available without the `std` feature.
     ///
     /// # Examples
     ///
@@ -56,9 +104,60 @@ impl OsError {
     /// println!("last OS error: {:?}", OsError::last_os_error());
     /// ```
     pub fn last_os_error() -> OsError {
-        OsError::new(io::Error::last_os_error().raw_os_error().unwrap())
+        OsError::new(Self::errno())
+    }
+
+    #[cfg(unix)]
+    fn errno() -> i32 {
+        unsafe { *libc::__errno_location() }
+    }
+
+    #[cfg(windows)]
+    fn errno() -> i32 {
+        unsafe { winapi::um::errhandlingapi::GetLastError() as i32 }
+    }
+
+    #[cfg(not(any(unix, windows)))]
+    fn errno() -> i32 {
+        -1
+    }
+
+    /// Sets the last OS error (`errno` on unix, the last-error value on
+    /// Windows) to `err`.
+    ///
+    /// This is needed when implementing FFI shims or mock syscalls that must
+    /// restore or inject a specific `errno` before returning to a C caller.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_error::OsError;
+    ///
+    /// let error = OsError::new(22);
+    /// OsError::set_last_os_error(error);
+    /// assert_eq!(OsError::last_os_error(), error);
+    /// ```
+    pub fn set_last_os_error(err: OsError) {
+        Self::set_errno(err.code);
     }
 
+    #[cfg(unix)]
+    fn set_errno(code: i32) {
+        unsafe {
+            *libc::__errno_location() = code;
+        }
+    }
+
+    #[cfg(windows)]
+    fn set_errno(code: i32) {
+        unsafe {
+            winapi::um::errhandlingapi::SetLastError(code as u32);
+        }
+    }
+
+    #[cfg(not(any(unix, windows)))]
+    fn set_errno(_code: i32) {}
+
     /// Returns the OS error that this error represents.
     ///
     /// # Examples
@@ -77,6 +176,8 @@ impl OsError {
 
     /// Returns the corresponding `ErrorKind` for this error.
     ///
+    /// Requires the `std` feature, since `io::ErrorKind` lives in `std::io`.
+    ///
     /// # Examples
     ///
     /// ```
@@ -87,15 +188,371 @@ impl OsError {
     ///     println!("{:?}", OsError::last_os_error());
     /// }
     /// ```
+    #[cfg(feature = "std")]
     pub fn kind(&self) -> io::ErrorKind {
         self.to_error().kind()
     }
 
+    #[cfg(feature = "std")]
+    #[allow(clippy::wrong_self_convention)]
     fn to_error(&self) -> io::Error {
         io::Error::from_raw_os_error(self.code)
     }
+
+    /// Writes a human-readable description of this error into `buf` and
+    /// returns the written portion as a `&str`.
+    ///
+    /// This calls `strerror_r` on POSIX platforms and `FormatMessageW` on
+    /// Windows (converting the UTF-16 result to UTF-8), so it works without
+    /// the `std` feature and without allocating. Returns `"Unknown error"`
+    /// (cut to the length of `buf` if `buf` is shorter than that text)
+    /// if `code` is negative or the underlying call fails for any reason.
+    /// On POSIX this includes `ERANGE` from a `buf` too small to hold the
+    /// message — that case is not distinguished from a truly unrecognized
+    /// code, so pass a buffer of at least 256 bytes to avoid a spurious
+    /// "Unknown error". On Windows a message longer than `buf` is cut short
+    /// instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use os_error::OsError;
+    ///
+    /// let mut buf = [0u8; 256];
+    /// let message = OsError::last_os_error().message_into(&mut buf);
+    /// println!("last OS error: {}", message);
+    /// ```
+    pub fn message_into<'a>(&self, buf: &'a mut [u8]) -> &'a str {
+        if self.code < 0 {
+            return Self::write_unknown(buf);
+        }
+
+        Self::message_into_platform(self.code, buf)
+    }
+
+    #[cfg(unix)]
+    fn message_into_platform(code: i32, buf: &mut [u8]) -> &str {
+        // `libc::strerror_r` binds the XSI-compliant (POSIX) signature on
+        // every unix target, including linux-gnu: it returns a `c_int`
+        // status and writes the message into `buf` itself, rather than
+        // returning a pointer to a static string.
+        let ptr = buf.as_mut_ptr() as *mut libc::c_char;
+        let cap = buf.len() as libc::size_t;
+
+        let ok = unsafe { libc::strerror_r(code, ptr, cap) == 0 };
+        if !ok {
+            return Self::write_unknown(buf);
+        }
+
+        let len = unsafe { libc::strlen(ptr) };
+        str::from_utf8(&buf[..len]).unwrap_or("Unknown error")
+    }
+
+    #[cfg(windows)]
+    fn message_into_platform(code: i32, buf: &mut [u8]) -> &str {
+        use core::char::decode_utf16;
+        use core::ptr;
+
+        let mut wide = [0u16; 512];
+        let len = unsafe {
+            winapi::um::winbase::FormatMessageW(
+                winapi::um::winbase::FORMAT_MESSAGE_FROM_SYSTEM
+                    | winapi::um::winbase::FORMAT_MESSAGE_IGNORE_INSERTS,
+                ptr::null(),
+                code as u32,
+                0,
+                wide.as_mut_ptr(),
+                wide.len() as u32,
+                ptr::null_mut(),
+            )
+        };
+
+        if len == 0 {
+            return Self::write_unknown(buf);
+        }
+
+        let mut n = 0;
+        for ch in decode_utf16(wide[..len as usize].iter().cloned()).filter_map(|c| c.ok()) {
+            let mut tmp = [0u8; 4];
+            let bytes = ch.encode_utf8(&mut tmp).as_bytes();
+            if n + bytes.len() > buf.len() {
+                break;
+            }
+            buf[n..n + bytes.len()].copy_from_slice(bytes);
+            n += bytes.len();
+        }
+        while n > 0 && (buf[n - 1] == b'\n' || buf[n - 1] == b'\r') {
+            n -= 1;
+        }
+
+        str::from_utf8(&buf[..n]).unwrap_or("Unknown error")
+    }
+
+    #[cfg(not(any(unix, windows)))]
+    fn message_into_platform(_code: i32, buf: &mut [u8]) -> &str {
+        Self::write_unknown(buf)
+    }
+
+    fn write_unknown(buf: &mut [u8]) -> &str {
+        let message = "Unknown error".as_bytes();
+        let len = message.len().min(buf.len());
+        buf[..len].copy_from_slice(&message[..len]);
+        str::from_utf8(&buf[..len]).unwrap_or("")
+    }
+
+    /// Returns the symbolic `errno` name for this error, e.g. `"EADDRINUSE"`.
+    ///
+    /// This is the bare constant name, not the prose message that `Display`
+    /// gives, which makes it useful for structured logging and error
+    /// matching. Returns `None` if the code is not in the lookup table for
+    /// the target platform; the tables list only common Linux `errno.h`
+    /// values and Windows system error codes, and on other platforms this
+    /// always returns `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # if cfg!(target_os = "linux") {
+    /// use os_error::OsError;
+    ///
+    /// let error = OsError::new(98);
+    /// assert_eq!(error.name(), Some("EADDRINUSE"));
+    /// # }
+    /// ```
+    pub fn name(&self) -> Option<&'static str> {
+        Self::lookup_name(self.code)
+    }
+
+    #[cfg(target_os = "linux")]
+    fn lookup_name(code: i32) -> Option<&'static str> {
+        LINUX_ERRNO_NAMES
+            .iter()
+            .find(|&&(c, _)| c == code)
+            .map(|&(_, name)| name)
+    }
+
+    #[cfg(windows)]
+    fn lookup_name(code: i32) -> Option<&'static str> {
+        WINDOWS_ERROR_NAMES
+            .iter()
+            .find(|&&(c, _)| c == code)
+            .map(|&(_, name)| name)
+    }
+
+    #[cfg(not(any(target_os = "linux", windows)))]
+    fn lookup_name(_code: i32) -> Option<&'static str> {
+        None
+    }
+
+    /// Classifies this error into a platform-independent category.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # if cfg!(target_os = "linux") {
+    /// use os_error::{OsError, PortableErrno};
+    ///
+    /// let error = OsError::new(98);
+    /// assert_eq!(error.to_kind_code(), PortableErrno::AddrInUse);
+    /// # }
+    /// ```
+    #[cfg(target_os = "linux")]
+    pub fn to_kind_code(&self) -> PortableErrno {
+        Self::portable_errno_for(Platform::Linux, self.code).unwrap_or(PortableErrno::Other)
+    }
+
+    /// Classifies this error into a platform-independent category.
+    #[cfg(windows)]
+    pub fn to_kind_code(&self) -> PortableErrno {
+        Self::portable_errno_for(Platform::Windows, self.code).unwrap_or(PortableErrno::Other)
+    }
+
+    /// Classifies this error into a platform-independent category.
+    #[cfg(not(any(target_os = "linux", windows)))]
+    pub fn to_kind_code(&self) -> PortableErrno {
+        PortableErrno::Other
+    }
+
+    /// Translates this error to the equivalent native code on `target`, if
+    /// one is known.
+    ///
+    /// For example, a Linux `OsError` for code `98` (`EADDRINUSE`)
+    /// translates to Windows code `10048` (`WSAEADDRINUSE`). This is useful
+    /// for comparing or replaying errors captured on different hosts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # if cfg!(target_os = "linux") {
+    /// use os_error::{OsError, Platform};
+    ///
+    /// let error = OsError::new(98);
+    /// assert_eq!(error.translate_to(Platform::Windows), Some(OsError::new(10048)));
+    /// # }
+    /// ```
+    #[cfg(target_os = "linux")]
+    pub fn translate_to(&self, target: Platform) -> Option<OsError> {
+        let kind = Self::portable_errno_for(Platform::Linux, self.code)?;
+        Self::code_for(kind, target).map(OsError::new)
+    }
+
+    /// Translates this error to the equivalent native code on `target`, if
+    /// one is known.
+    #[cfg(windows)]
+    pub fn translate_to(&self, target: Platform) -> Option<OsError> {
+        let kind = Self::portable_errno_for(Platform::Windows, self.code)?;
+        Self::code_for(kind, target).map(OsError::new)
+    }
+
+    /// Translates this error to the equivalent native code on `target`, if
+    /// one is known.
+    #[cfg(not(any(target_os = "linux", windows)))]
+    pub fn translate_to(&self, _target: Platform) -> Option<OsError> {
+        None
+    }
+
+    #[cfg(any(target_os = "linux", windows))]
+    fn portable_errno_for(platform: Platform, code: i32) -> Option<PortableErrno> {
+        PORTABLE_ERRNO_TABLE
+            .iter()
+            .find(|&&(_, linux, windows)| match platform {
+                Platform::Linux => linux == Some(code),
+                Platform::Windows => windows == Some(code),
+            })
+            .map(|&(kind, _, _)| kind)
+    }
+
+    #[cfg(any(target_os = "linux", windows))]
+    fn code_for(kind: PortableErrno, platform: Platform) -> Option<i32> {
+        PORTABLE_ERRNO_TABLE
+            .iter()
+            .find(|&&(k, _, _)| k == kind)
+            .and_then(|&(_, linux, windows)| match platform {
+                Platform::Linux => linux,
+                Platform::Windows => windows,
+            })
+    }
 }
 
+/// Bidirectional mapping between [`PortableErrno`] categories and the native
+/// Linux/Windows codes that represent them.
+#[cfg(any(target_os = "linux", windows))]
+static PORTABLE_ERRNO_TABLE: &[(PortableErrno, Option<i32>, Option<i32>)] = &[
+    (PortableErrno::NotFound, Some(2), Some(2)),
+    (PortableErrno::PermissionDenied, Some(13), Some(5)),
+    (PortableErrno::ConnectionRefused, Some(111), Some(10061)),
+    (PortableErrno::ConnectionReset, Some(104), Some(10054)),
+    (PortableErrno::ConnectionAborted, Some(103), Some(10053)),
+    (PortableErrno::NotConnected, Some(107), Some(10057)),
+    (PortableErrno::AddrInUse, Some(98), Some(10048)),
+    (PortableErrno::AddrNotAvailable, Some(99), Some(10049)),
+    (PortableErrno::BrokenPipe, Some(32), Some(109)),
+    (PortableErrno::AlreadyExists, Some(17), Some(183)),
+    (PortableErrno::WouldBlock, Some(11), Some(10035)),
+    (PortableErrno::InvalidInput, Some(22), Some(87)),
+    (PortableErrno::TimedOut, Some(110), Some(10060)),
+    (PortableErrno::Interrupted, Some(4), Some(10004)),
+];
+
+/// Symbolic names for common Linux `errno.h` values, sorted by code.
+#[cfg(target_os = "linux")]
+static LINUX_ERRNO_NAMES: &[(i32, &str)] = &[
+    (1, "EPERM"),
+    (2, "ENOENT"),
+    (3, "ESRCH"),
+    (4, "EINTR"),
+    (5, "EIO"),
+    (6, "ENXIO"),
+    (7, "E2BIG"),
+    (8, "ENOEXEC"),
+    (9, "EBADF"),
+    (10, "ECHILD"),
+    (11, "EAGAIN"),
+    (12, "ENOMEM"),
+    (13, "EACCES"),
+    (14, "EFAULT"),
+    (16, "EBUSY"),
+    (17, "EEXIST"),
+    (18, "EXDEV"),
+    (19, "ENODEV"),
+    (20, "ENOTDIR"),
+    (21, "EISDIR"),
+    (22, "EINVAL"),
+    (23, "ENFILE"),
+    (24, "EMFILE"),
+    (25, "ENOTTY"),
+    (27, "EFBIG"),
+    (28, "ENOSPC"),
+    (29, "ESPIPE"),
+    (30, "EROFS"),
+    (31, "EMLINK"),
+    (32, "EPIPE"),
+    (36, "ENAMETOOLONG"),
+    (38, "ENOSYS"),
+    (39, "ENOTEMPTY"),
+    (61, "ENODATA"),
+    (84, "EILSEQ"),
+    (90, "EMSGSIZE"),
+    (95, "EOPNOTSUPP"),
+    (97, "EAFNOSUPPORT"),
+    (98, "EADDRINUSE"),
+    (99, "EADDRNOTAVAIL"),
+    (100, "ENETDOWN"),
+    (101, "ENETUNREACH"),
+    (102, "ENETRESET"),
+    (103, "ECONNABORTED"),
+    (104, "ECONNRESET"),
+    (105, "ENOBUFS"),
+    (106, "EISCONN"),
+    (107, "ENOTCONN"),
+    (108, "ESHUTDOWN"),
+    (110, "ETIMEDOUT"),
+    (111, "ECONNREFUSED"),
+    (112, "EHOSTDOWN"),
+    (113, "EHOSTUNREACH"),
+    (114, "EALREADY"),
+    (115, "EINPROGRESS"),
+    (116, "ESTALE"),
+];
+
+/// Symbolic names for common Windows system error codes, sorted by code.
+#[cfg(windows)]
+static WINDOWS_ERROR_NAMES: &[(i32, &str)] = &[
+    (2, "ERROR_FILE_NOT_FOUND"),
+    (3, "ERROR_PATH_NOT_FOUND"),
+    (5, "ERROR_ACCESS_DENIED"),
+    (6, "ERROR_INVALID_HANDLE"),
+    (8, "ERROR_NOT_ENOUGH_MEMORY"),
+    (32, "ERROR_SHARING_VIOLATION"),
+    (87, "ERROR_INVALID_PARAMETER"),
+    (183, "ERROR_ALREADY_EXISTS"),
+    (995, "ERROR_OPERATION_ABORTED"),
+    (997, "ERROR_IO_PENDING"),
+    (10004, "WSAEINTR"),
+    (10009, "WSAEBADF"),
+    (10013, "WSAEACCES"),
+    (10014, "WSAEFAULT"),
+    (10022, "WSAEINVAL"),
+    (10035, "WSAEWOULDBLOCK"),
+    (10036, "WSAEINPROGRESS"),
+    (10048, "WSAEADDRINUSE"),
+    (10049, "WSAEADDRNOTAVAIL"),
+    (10050, "WSAENETDOWN"),
+    (10051, "WSAENETUNREACH"),
+    (10052, "WSAENETRESET"),
+    (10053, "WSAECONNABORTED"),
+    (10054, "WSAECONNRESET"),
+    (10055, "WSAENOBUFS"),
+    (10056, "WSAEISCONN"),
+    (10057, "WSAENOTCONN"),
+    (10058, "WSAESHUTDOWN"),
+    (10060, "WSAETIMEDOUT"),
+    (10061, "WSAECONNREFUSED"),
+    (10064, "WSAEHOSTDOWN"),
+    (10065, "WSAEHOSTUNREACH"),
+];
+
+#[cfg(feature = "std")]
 impl fmt::Debug for OsError {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
         let error: io::Error = self.to_error();
@@ -107,13 +564,21 @@ impl fmt::Debug for OsError {
     }
 }
 
+#[cfg(not(feature = "std"))]
+impl fmt::Debug for OsError {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct("OsError").field("code", &self.code).finish()
+    }
+}
+
 impl fmt::Display for OsError {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
-        write!(fmt, "{}", &self.to_error())
+        let mut buf = [0u8; 256];
+        write!(fmt, "{} (os error {})", self.message_into(&mut buf), self.code)
     }
 }
 
-#[cfg(feature = "nightly")]
+#[cfg(all(feature = "nightly", feature = "std"))]
 impl TryFrom<io::Error> for OsError {
     type Error = NoOsError;
 
@@ -125,14 +590,23 @@ impl TryFrom<io::Error> for OsError {
     }
 }
 
-impl Into<io::Error> for OsError {
-    fn into(self) -> io::Error {
-        self.to_error()
+#[cfg(feature = "std")]
+impl From<OsError> for io::Error {
+    fn from(error: OsError) -> io::Error {
+        error.to_error()
     }
 }
 
+// `#![no_std]` also drops the implicit `extern crate std;` when the crate
+// is built for `cargo test`, but the test harness still links std, so
+// declaring it here lets the test module use it regardless of the `std`
+// feature.
+#[cfg(test)]
+extern crate std;
+
 #[cfg(test)]
 mod tests {
+    use std::format;
     use std::io;
     use super::OsError;
     #[cfg(feature = "nightly")]
@@ -153,14 +627,73 @@ mod tests {
     }
 
     #[test]
+    #[cfg(target_os = "linux")]
+    fn test_message_into() {
+        let err = OsError::new(CODE);
+        let mut buf = [0u8; 256];
+
+        assert_eq!(err.message_into(&mut buf), "No such device or address");
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_to_kind_code() {
+        use super::PortableErrno;
+
+        assert_eq!(OsError::new(98).to_kind_code(), PortableErrno::AddrInUse);
+        assert_eq!(OsError::new(-1).to_kind_code(), PortableErrno::Other);
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_translate_to() {
+        use super::Platform;
+
+        assert_eq!(
+            OsError::new(98).translate_to(Platform::Windows),
+            Some(OsError::new(10048))
+        );
+        assert_eq!(
+            OsError::new(98).translate_to(Platform::Linux),
+            Some(OsError::new(98))
+        );
+        assert_eq!(OsError::new(-1).translate_to(Platform::Windows), None);
+    }
+
+    #[test]
+    fn test_set_last_os_error() {
+        let error = OsError::new(CODE);
+        OsError::set_last_os_error(error);
+        assert_eq!(OsError::last_os_error(), error);
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_name() {
+        assert_eq!(OsError::new(98).name(), Some("EADDRINUSE"));
+        assert_eq!(OsError::new(CODE).name(), Some("ENXIO"));
+        assert_eq!(OsError::new(-1).name(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
     fn test_fmt_debug() {
-        let kind = io::ErrorKind::Other;
+        let kind = io::Error::from_raw_os_error(CODE).kind();
         let err = OsError::new(CODE);
 
         let expected = format!("OsError {{ code: {:?}, kind: {:?} }}", CODE, kind);
         assert_eq!(format!("{:?}", err), expected);
     }
 
+    #[test]
+    #[cfg(not(feature = "std"))]
+    fn test_fmt_debug() {
+        let err = OsError::new(CODE);
+
+        let expected = format!("OsError {{ code: {:?} }}", CODE);
+        assert_eq!(format!("{:?}", err), expected);
+    }
+
     #[test]
     #[cfg(feature = "nightly")]
     fn from_io_error() {